@@ -0,0 +1,133 @@
+#[cfg(feature = "dice")]
+use rand::Rng;
+
+#[cfg(feature = "dice")]
+use crate::Die;
+
+/// This struct represents the six ability scores chosen during character
+/// creation, in the order Strength, Dexterity, Constitution, Intelligence,
+/// Wisdom, and Charisma.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct AbilityScoreArray([u8; 6]);
+
+impl AbilityScoreArray {
+    /// The number of points available to spend when using point buy.
+    pub const POINT_BUY_BUDGET: u32 = 27;
+    /// The scores of the standard array, from highest to lowest.
+    pub const STANDARD_ARRAY: [u8; 6] = [15, 14, 13, 12, 10, 8];
+
+    /// Creates a new ability score array from the given scores.
+    #[must_use]
+    pub const fn new(scores: [u8; 6]) -> Self {
+        Self(scores)
+    }
+
+    /// Returns the scores in the array.
+    #[must_use]
+    pub const fn scores(self) -> [u8; 6] {
+        self.0
+    }
+
+    /// Returns `true` if the scores are the standard array assigned in any
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::AbilityScoreArray;
+    ///
+    /// assert!(AbilityScoreArray::new([8, 14, 13, 15, 10, 12]).is_standard_array());
+    /// assert!(!AbilityScoreArray::new([15, 15, 13, 12, 10, 8]).is_standard_array());
+    /// ```
+    #[must_use]
+    pub fn is_standard_array(self) -> bool {
+        let mut scores = self.0;
+        scores.sort_unstable_by(|a, b| b.cmp(a));
+        scores == Self::STANDARD_ARRAY
+    }
+
+    /// Returns the total point buy cost of the scores, or `None` if any score
+    /// is outside the range of 8 to 15 that can be bought. The cost is not
+    /// checked against [`Self::POINT_BUY_BUDGET`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::AbilityScoreArray;
+    ///
+    /// let array = AbilityScoreArray::new([15, 15, 15, 8, 8, 8]);
+    /// assert_eq!(array.point_buy_cost(), Some(27));
+    ///
+    /// let array = AbilityScoreArray::new([15, 15, 15, 15, 8, 8]);
+    /// assert!(array.point_buy_cost().unwrap() > AbilityScoreArray::POINT_BUY_BUDGET);
+    ///
+    /// let array = AbilityScoreArray::new([16, 14, 13, 12, 10, 8]);
+    /// assert_eq!(array.point_buy_cost(), None);
+    /// ```
+    #[must_use]
+    pub fn point_buy_cost(self) -> Option<u32> {
+        self.0.iter().map(|&score| point_buy_cost(score)).sum()
+    }
+}
+
+/// Returns the point buy cost of a single score, or `None` if it can't be
+/// bought.
+const fn point_buy_cost(score: u8) -> Option<u32> {
+    match score {
+        8 => Some(0),
+        9 => Some(1),
+        10 => Some(2),
+        11 => Some(3),
+        12 => Some(4),
+        13 => Some(5),
+        14 => Some(7),
+        15 => Some(9),
+        _ => None,
+    }
+}
+
+/// Returns an ability score rolled by rolling four d6 and adding together the
+/// highest three, so the score is always from 3 to 18.
+///
+/// This function is only available with the `dice` feature.
+///
+/// # Examples
+///
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use sw5e::roll_4d6_drop_lowest;
+///
+/// let mut rng = StdRng::seed_from_u64(170);
+/// for _ in 0..1000 {
+///     assert!((3..=18).contains(&roll_4d6_drop_lowest(&mut rng)));
+/// }
+/// ```
+#[cfg(feature = "dice")]
+pub fn roll_4d6_drop_lowest<R: Rng>(rng: &mut R) -> u8 {
+    let rolls: [u32; 4] = core::array::from_fn(|_| Die::D6.roll(rng));
+    let lowest = rolls.iter().min().copied().unwrap_or_default();
+    let total = rolls.iter().sum::<u32>() - lowest;
+    u8::try_from(total).unwrap_or(u8::MAX)
+}
+
+/// Returns an ability score array of six scores, each rolled with
+/// [`roll_4d6_drop_lowest`].
+///
+/// This function is only available with the `dice` feature.
+///
+/// # Examples
+///
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use sw5e::roll_ability_array;
+///
+/// let mut rng = StdRng::seed_from_u64(170);
+/// let array = roll_ability_array(&mut rng);
+/// assert!(array.scores().iter().all(|score| (3..=18).contains(score)));
+/// ```
+#[cfg(feature = "dice")]
+pub fn roll_ability_array<R: Rng>(rng: &mut R) -> AbilityScoreArray {
+    AbilityScoreArray::new(core::array::from_fn(|_| roll_4d6_drop_lowest(rng)))
+}
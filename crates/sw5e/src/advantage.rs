@@ -0,0 +1,192 @@
+#[cfg(feature = "dice")]
+use rand::Rng;
+
+#[cfg(feature = "dice")]
+use crate::Die;
+use crate::Proficency;
+
+/// This enum represents whether a d20 roll is made with advantage,
+/// disadvantage, or neither.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum AdvantageState {
+    /// The roll is made with disadvantage, and the lower of two d20s is used.
+    Disadvantage,
+    /// The roll is made normally with a single d20.
+    #[default]
+    Normal,
+    /// The roll is made with advantage, and the higher of two d20s is used.
+    Advantage,
+}
+
+impl AdvantageState {
+    /// Returns the state of a roll affected by any number of sources of
+    /// advantage and disadvantage. Sources don't stack: if there is at least
+    /// one of each, they cancel out and the roll is made normally.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::AdvantageState;
+    ///
+    /// assert_eq!(
+    ///     AdvantageState::from_sources(true, false),
+    ///     AdvantageState::Advantage
+    /// );
+    /// assert_eq!(
+    ///     AdvantageState::from_sources(false, true),
+    ///     AdvantageState::Disadvantage
+    /// );
+    /// assert_eq!(
+    ///     AdvantageState::from_sources(true, true),
+    ///     AdvantageState::Normal
+    /// );
+    /// assert_eq!(
+    ///     AdvantageState::from_sources(false, false),
+    ///     AdvantageState::Normal
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn from_sources(advantage: bool, disadvantage: bool) -> Self {
+        match (advantage, disadvantage) {
+            (true, false) => Self::Advantage,
+            (false, true) => Self::Disadvantage,
+            _ => Self::Normal,
+        }
+    }
+}
+
+/// Returns the chance of rolling a critical hit at the given proficency level,
+/// where a d20 roll of `crit_range` or higher is a critical hit. If the level
+/// grants advantage, the higher of two d20s is used.
+///
+/// A `crit_range` of 0 or 1 always results in a critical hit, and a
+/// `crit_range` above 20 never does.
+///
+/// # Examples
+///
+/// ```
+/// use sw5e::{crit_probability, Proficency};
+///
+/// assert_eq!(crit_probability(Proficency::Proficent, 20), 0.05);
+/// assert!((crit_probability(Proficency::Mastery, 20) - 0.0975).abs() < 1e-12);
+/// assert_eq!(crit_probability(Proficency::Proficent, 19), 0.1);
+/// assert_eq!(crit_probability(Proficency::Mastery, 21), 0.0);
+/// ```
+#[must_use]
+pub fn crit_probability(tier: Proficency, crit_range: u8) -> f64 {
+    let crit_faces = 21 - crit_range.clamp(1, 21);
+    let single = f64::from(crit_faces) / 20.0;
+
+    if tier.has_advantage() {
+        1.0 - (1.0 - single) * (1.0 - single)
+    } else {
+        single
+    }
+}
+
+/// Returns `true` if a die that rolled `die_value` should be rerolled at the
+/// given proficency level, under the house rule that dice are only rerolled
+/// when they roll `threshold` or lower. Only levels that allow rerolls at all
+/// can reroll.
+///
+/// # Examples
+///
+/// ```
+/// use sw5e::{should_reroll, Proficency};
+///
+/// assert!(should_reroll(Proficency::GrandMastery, 1, 1));
+/// assert!(!should_reroll(Proficency::GrandMastery, 5, 1));
+/// assert!(!should_reroll(Proficency::Mastery, 1, 1));
+/// ```
+#[must_use]
+pub const fn should_reroll(tier: Proficency, die_value: u32, threshold: u32) -> bool {
+    tier.reroll_dice_count() > 0 && die_value <= threshold
+}
+
+/// Returns the result of a die that rolled `die_value` for a feature like
+/// reliable talent, which treats rolls below `floor` as `floor` on checks
+/// made at `Proficent` or above. Lower proficency levels use the roll as is.
+///
+/// # Examples
+///
+/// ```
+/// use sw5e::{apply_reliable_talent, Proficency};
+///
+/// assert_eq!(apply_reliable_talent(7, Proficency::Proficent, 10), 10);
+/// assert_eq!(apply_reliable_talent(7, Proficency::Trained, 10), 7);
+/// assert_eq!(apply_reliable_talent(15, Proficency::Expertise, 10), 15);
+/// ```
+#[must_use]
+pub const fn apply_reliable_talent(die_value: u32, tier: Proficency, floor: u32) -> u32 {
+    if tier as u8 >= Proficency::Proficent as u8 && die_value < floor {
+        floor
+    } else {
+        die_value
+    }
+}
+
+/// Returns `true` if initiative is rolled with advantage, either because the
+/// proficency level grants it or because of a feat like Alert.
+///
+/// # Examples
+///
+/// ```
+/// use sw5e::{initiative_advantage, Proficency};
+///
+/// assert!(initiative_advantage(Proficency::Mastery, false));
+/// assert!(initiative_advantage(Proficency::Untrained, true));
+/// assert!(!initiative_advantage(Proficency::Expertise, false));
+/// ```
+#[must_use]
+pub const fn initiative_advantage(tier: Proficency, alert_feat: bool) -> bool {
+    tier.has_advantage() || alert_feat
+}
+
+/// Rolls two of the given die for a roll made with advantage at the given
+/// proficency level, returning both dice and the one that is kept, in the
+/// form `(first, second, kept)`.
+///
+/// Any rerolls the level allows are applied before the dice are returned, and
+/// the kept die is always the higher of the two. At `HighMastery` the lower
+/// die is rerolled, and at `GrandMastery` each die is rerolled once. The new
+/// rolls are always used.
+///
+/// This function is only available with the `dice` feature.
+///
+/// # Examples
+///
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use sw5e::{resolve_advantage_detailed, Die, Proficency};
+///
+/// let mut rng = StdRng::seed_from_u64(197);
+/// for tier in Proficency::ALL {
+///     for _ in 0..100 {
+///         let (first, second, kept) = resolve_advantage_detailed(&mut rng, Die::D20, tier);
+///         assert_eq!(kept, first.max(second));
+///         assert!((1..=20).contains(&first) && (1..=20).contains(&second));
+///     }
+/// }
+/// ```
+#[cfg(feature = "dice")]
+pub fn resolve_advantage_detailed<R: Rng>(
+    rng: &mut R,
+    die: Die,
+    tier: Proficency,
+) -> (u32, u32, u32) {
+    let mut first = die.roll(rng);
+    let mut second = die.roll(rng);
+
+    match tier.reroll_dice_count() {
+        0 => {}
+        1 if first < second => first = die.roll(rng),
+        1 => second = die.roll(rng),
+        _ => {
+            first = die.roll(rng);
+            second = die.roll(rng);
+        }
+    }
+
+    (first, second, first.max(second))
+}
@@ -0,0 +1,130 @@
+use crate::CustomTier;
+
+/// This enum represents the rule used to determine a character's base
+/// proficiency bonus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum BonusRule {
+    /// The base proficiency bonus is determined by the character's level, as
+    /// in the Player's Handbook.
+    #[default]
+    ByLevel,
+    /// The base proficiency bonus is the given value regardless of level. This
+    /// is a common variant rule.
+    Flat(i32),
+}
+
+impl BonusRule {
+    /// Returns the base proficiency bonus at the given level under this rule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::BonusRule;
+    ///
+    /// assert_eq!(BonusRule::ByLevel.base_bonus(5), 3);
+    /// assert_eq!(BonusRule::Flat(4).base_bonus(5), 4);
+    /// ```
+    #[must_use]
+    pub const fn base_bonus(self, level: u8) -> i32 {
+        match self {
+            Self::ByLevel => proficiency_bonus_for_level(level),
+            Self::Flat(bonus) => bonus,
+        }
+    }
+}
+
+/// Returns the base proficiency bonus of a character of the given level.
+/// Levels below 1 are treated as 1, and levels above 20 are treated as 20.
+///
+/// # Examples
+///
+/// ```
+/// use sw5e::proficiency_bonus_for_level;
+///
+/// assert_eq!(proficiency_bonus_for_level(1), 2);
+/// assert_eq!(proficiency_bonus_for_level(4), 2);
+/// assert_eq!(proficiency_bonus_for_level(5), 3);
+/// assert_eq!(proficiency_bonus_for_level(17), 6);
+/// assert_eq!(proficiency_bonus_for_level(20), 6);
+/// ```
+#[must_use]
+pub const fn proficiency_bonus_for_level(level: u8) -> i32 {
+    match level {
+        0..=4 => 2,
+        5..=8 => 3,
+        9..=12 => 4,
+        13..=16 => 5,
+        _ => 6,
+    }
+}
+
+/// Returns the proficiency bonus of a creature of the given challenge rating.
+/// Fractional challenge ratings should be passed as 0, and challenge ratings
+/// above 30 are treated as 30.
+///
+/// # Examples
+///
+/// ```
+/// use sw5e::proficiency_bonus_for_cr;
+///
+/// assert_eq!(proficiency_bonus_for_cr(0), 2);
+/// assert_eq!(proficiency_bonus_for_cr(4), 2);
+/// assert_eq!(proficiency_bonus_for_cr(5), 3);
+/// assert_eq!(proficiency_bonus_for_cr(17), 6);
+/// assert_eq!(proficiency_bonus_for_cr(30), 9);
+/// ```
+#[must_use]
+pub const fn proficiency_bonus_for_cr(cr: u8) -> i32 {
+    match cr {
+        0..=4 => 2,
+        5..=8 => 3,
+        9..=12 => 4,
+        13..=16 => 5,
+        17..=20 => 6,
+        21..=24 => 7,
+        25..=28 => 8,
+        _ => 9,
+    }
+}
+
+/// Returns the total bonus to a check made with the given ability modifier at
+/// the given tier of proficency and character level.
+///
+/// # Examples
+///
+/// ```
+/// use sw5e::{check_bonus, Proficency};
+///
+/// assert_eq!(check_bonus(3, Proficency::Untrained, 5), 3);
+/// assert_eq!(check_bonus(3, Proficency::Expertise, 5), 9);
+/// assert_eq!(check_bonus(-1, Proficency::Trained, 1), 0);
+///
+/// for tier in Proficency::ALL {
+///     assert_eq!(check_bonus(0, tier, 9), tier.bonus(9));
+/// }
+/// ```
+#[must_use]
+// Tiers are taken by value, like `Proficency` itself, so that callers don't
+// have to borrow them.
+#[allow(clippy::needless_pass_by_value)]
+pub fn check_bonus<T: CustomTier>(ability_mod: i32, tier: T, level: u8) -> i32 {
+    let base = proficiency_bonus_for_level(level);
+    ability_mod + (base * tier.scale_numerator()).div_euclid(tier.scale_denominator())
+}
+
+/// Returns the DC an opponent must beat to win a check opposed by a creature
+/// with the given ability modifier, tier of proficency, and character level.
+/// This is the "take 10" equivalent of the check: 10 plus its
+/// [`check_bonus`].
+///
+/// # Examples
+///
+/// ```
+/// use sw5e::{opposed_dc, Proficency};
+///
+/// assert_eq!(opposed_dc(3, Proficency::Proficent, 5), 16);
+/// ```
+#[must_use]
+pub fn opposed_dc<T: CustomTier>(ability_mod: i32, tier: T, level: u8) -> i32 {
+    10 + check_bonus(ability_mod, tier, level)
+}
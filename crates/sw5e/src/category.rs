@@ -0,0 +1,152 @@
+use core::fmt;
+use std::error::Error;
+
+use crate::Proficency;
+
+/// This enum represents the categories that proficency can be obtained in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ProficiencyCategory {
+    /// Skills, such as Athletics or Stealth.
+    Skill,
+    /// Tools, such as a slicer's kit.
+    Tool,
+    /// Saving throws.
+    SavingThrow,
+    /// Weapons.
+    Weapon,
+}
+
+impl ProficiencyCategory {
+    /// Returns the highest proficency level that can be obtained in this
+    /// category.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::{Proficency, ProficiencyCategory};
+    ///
+    /// assert_eq!(
+    ///     ProficiencyCategory::Skill.max_tier(),
+    ///     Proficency::GrandMastery
+    /// );
+    /// assert_eq!(
+    ///     ProficiencyCategory::Weapon.max_tier(),
+    ///     Proficency::Proficent
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn max_tier(self) -> Proficency {
+        match self {
+            Self::Skill | Self::Tool | Self::SavingThrow => Proficency::GrandMastery,
+            Self::Weapon => Proficency::Proficent,
+        }
+    }
+}
+
+/// This struct represents a proficency level in a skill. Every proficency
+/// level can be obtained in skills.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct SkillProficiency(Proficency);
+
+impl SkillProficiency {
+    /// Creates a new skill proficency at the given level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::{Proficency, SkillProficiency};
+    ///
+    /// let proficency = SkillProficiency::new(Proficency::GrandMastery);
+    /// assert_eq!(proficency.tier(), Proficency::GrandMastery);
+    /// ```
+    #[must_use]
+    pub const fn new(tier: Proficency) -> Self {
+        Self(tier)
+    }
+
+    /// Returns the proficency level.
+    #[must_use]
+    pub const fn tier(self) -> Proficency {
+        self.0
+    }
+}
+
+/// This struct represents a proficency level in a weapon, which can't be
+/// higher than `Proficent`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct WeaponProficiency(Proficency);
+
+impl WeaponProficiency {
+    /// Creates a new weapon proficency at the given level.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the level can't be obtained in weapons.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::{Proficency, WeaponProficiency};
+    ///
+    /// let proficency = WeaponProficiency::new(Proficency::Proficent).unwrap();
+    /// assert_eq!(proficency.tier(), Proficency::Proficent);
+    /// assert!(WeaponProficiency::new(Proficency::Expertise).is_err());
+    /// assert!(WeaponProficiency::new(Proficency::Mastery).is_err());
+    /// ```
+    pub const fn new(tier: Proficency) -> Result<Self, CategoryError> {
+        if tier.can_be_applied_to(ProficiencyCategory::Weapon) {
+            Ok(Self(tier))
+        } else {
+            Err(CategoryError::new(tier, ProficiencyCategory::Weapon))
+        }
+    }
+
+    /// Returns the proficency level.
+    #[must_use]
+    pub const fn tier(self) -> Proficency {
+        self.0
+    }
+}
+
+/// The error returned when a proficency level can't be obtained in a
+/// category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CategoryError {
+    tier: Proficency,
+    category: ProficiencyCategory,
+}
+
+impl CategoryError {
+    /// Creates a new error for the given proficency level and category.
+    #[must_use]
+    pub const fn new(tier: Proficency, category: ProficiencyCategory) -> Self {
+        Self { tier, category }
+    }
+
+    /// Returns the proficency level that can't be obtained.
+    #[must_use]
+    pub const fn tier(&self) -> Proficency {
+        self.tier
+    }
+
+    /// Returns the category the proficency level can't be obtained in.
+    #[must_use]
+    pub const fn category(&self) -> ProficiencyCategory {
+        self.category
+    }
+}
+
+impl fmt::Display for CategoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let category = match self.category {
+            ProficiencyCategory::Skill => "skills",
+            ProficiencyCategory::Tool => "tools",
+            ProficiencyCategory::SavingThrow => "saving throws",
+            ProficiencyCategory::Weapon => "weapons",
+        };
+
+        write!(f, "{} cannot be obtained in {category}", self.tier)
+    }
+}
+
+impl Error for CategoryError {}
@@ -0,0 +1,61 @@
+use crate::Proficency;
+
+/// This trait describes how a tier of proficency affects a roll, so that
+/// house-ruled tiers can be used anywhere a [`Proficency`] can.
+///
+/// A tier adds the base proficiency bonus multiplied by
+/// `scale_numerator / scale_denominator`, rounded down.
+///
+/// # Examples
+///
+/// ```
+/// use sw5e::{check_bonus, CustomTier, Proficency};
+///
+/// /// A tier between `Untrained` and `Trained` that adds a quarter of the
+/// /// proficiency bonus.
+/// struct Apprentice;
+///
+/// impl CustomTier for Apprentice {
+///     fn scale_numerator(&self) -> i32 {
+///         1
+///     }
+///
+///     fn scale_denominator(&self) -> i32 {
+///         4
+///     }
+///
+///     fn has_advantage(&self) -> bool {
+///         false
+///     }
+/// }
+///
+/// assert_eq!(check_bonus(2, Apprentice, 1), 2);
+/// assert_eq!(check_bonus(2, Apprentice, 17), 3);
+/// assert_eq!(check_bonus(2, Proficency::Trained, 17), 5);
+/// ```
+pub trait CustomTier {
+    /// Returns the numerator of the multiplier applied to the proficiency
+    /// bonus.
+    fn scale_numerator(&self) -> i32;
+
+    /// Returns the denominator of the multiplier applied to the proficiency
+    /// bonus. This must not be 0.
+    fn scale_denominator(&self) -> i32;
+
+    /// Returns `true` if rolls at this tier are always made with advantage.
+    fn has_advantage(&self) -> bool;
+}
+
+impl CustomTier for Proficency {
+    fn scale_numerator(&self) -> i32 {
+        self.doubled_scale()
+    }
+
+    fn scale_denominator(&self) -> i32 {
+        2
+    }
+
+    fn has_advantage(&self) -> bool {
+        Proficency::has_advantage(*self)
+    }
+}
@@ -0,0 +1,107 @@
+#[cfg(feature = "dice")]
+use rand::Rng;
+
+/// This enum represents the dice rolled in Star Wars 5e.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Die {
+    /// A four-sided die.
+    D4,
+    /// A six-sided die.
+    D6,
+    /// An eight-sided die.
+    D8,
+    /// A ten-sided die.
+    D10,
+    /// A twelve-sided die.
+    D12,
+    /// A twenty-sided die.
+    D20,
+    /// A hundred-sided die, usually rolled as a pair of d10s.
+    D100,
+}
+
+impl Die {
+    /// Returns the number of sides on the die, which is also the highest value
+    /// it can roll.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::Die;
+    ///
+    /// assert_eq!(Die::D4.sides(), 4);
+    /// assert_eq!(Die::D20.sides(), 20);
+    /// ```
+    #[must_use]
+    pub const fn sides(self) -> u32 {
+        match self {
+            Self::D4 => 4,
+            Self::D6 => 6,
+            Self::D8 => 8,
+            Self::D10 => 10,
+            Self::D12 => 12,
+            Self::D20 => 20,
+            Self::D100 => 100,
+        }
+    }
+
+    /// Rolls the die, returning a value from 1 to [`Self::sides`].
+    ///
+    /// This function is only available with the `dice` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    /// use sw5e::Die;
+    ///
+    /// let mut rng = StdRng::seed_from_u64(121);
+    /// for _ in 0..100 {
+    ///     assert!((1..=6).contains(&Die::D6.roll(&mut rng)));
+    /// }
+    /// ```
+    #[cfg(feature = "dice")]
+    pub fn roll<R: Rng>(self, rng: &mut R) -> u32 {
+        rng.gen_range(1..=self.sides())
+    }
+
+    /// Rolls the die as an exploding die: whenever it rolls its highest value,
+    /// it's rolled again and the new roll is added to the total. At most
+    /// `max_explosions` extra rolls are made, so the result is never more than
+    /// [`Self::sides`] times `max_explosions + 1`.
+    ///
+    /// This function is only available with the `dice` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    /// use sw5e::Die;
+    ///
+    /// let mut rng = StdRng::seed_from_u64(192);
+    /// let rolls: Vec<_> = (0..1000)
+    ///     .map(|_| Die::D4.roll_exploding(&mut rng, 2))
+    ///     .collect();
+    /// assert!(rolls.iter().any(|&roll| roll > 4));
+    /// assert!(rolls.contains(&12));
+    /// assert!(rolls.iter().all(|&roll| (1..=12).contains(&roll)));
+    ///
+    /// for _ in 0..1000 {
+    ///     assert!(Die::D4.roll_exploding(&mut rng, 0) <= 4);
+    /// }
+    /// ```
+    #[cfg(feature = "dice")]
+    pub fn roll_exploding<R: Rng>(self, rng: &mut R, max_explosions: u8) -> u32 {
+        let mut total = 0;
+        for _ in 0..=max_explosions {
+            let roll = self.roll(rng);
+            total += roll;
+            if roll < self.sides() {
+                break;
+            }
+        }
+        total
+    }
+}
@@ -0,0 +1,164 @@
+use core::fmt;
+use std::error::Error;
+
+#[cfg(feature = "dice")]
+use rand::Rng;
+
+use crate::Die;
+
+/// This struct tracks the hit dice a character has available for healing
+/// during short rests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HitDicePool {
+    die: Die,
+    total: u8,
+    spent: u8,
+}
+
+impl HitDicePool {
+    /// Creates a new pool of `total` hit dice of the given die, none of which
+    /// have been spent. The die is the hit die of the character's class.
+    #[must_use]
+    pub const fn new(die: Die, total: u8) -> Self {
+        Self {
+            die,
+            total,
+            spent: 0,
+        }
+    }
+
+    /// Returns the die rolled when spending a hit die.
+    #[must_use]
+    pub const fn die(&self) -> Die {
+        self.die
+    }
+
+    /// Returns the total number of hit dice in the pool.
+    #[must_use]
+    pub const fn total(&self) -> u8 {
+        self.total
+    }
+
+    /// Returns the number of hit dice that have been spent.
+    #[must_use]
+    pub const fn spent(&self) -> u8 {
+        self.spent
+    }
+
+    /// Returns the number of hit dice that can still be spent.
+    #[must_use]
+    pub const fn remaining(&self) -> u8 {
+        self.total - self.spent
+    }
+
+    /// Spends `count` hit dice, returning the sum of rolling that many of
+    /// [`Self::die`].
+    ///
+    /// This function is only available with the `dice` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, and leaves the pool unchanged, if fewer than `count`
+    /// hit dice remain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    /// use sw5e::{Die, HitDicePool};
+    ///
+    /// let mut rng = StdRng::seed_from_u64(121);
+    /// let mut pool = HitDicePool::new(Die::D8, 3);
+    ///
+    /// let healed = pool.spend(&mut rng, 2).unwrap();
+    /// assert!((2..=16).contains(&healed));
+    /// assert_eq!(pool.remaining(), 1);
+    ///
+    /// assert!(pool.spend(&mut rng, 2).is_err());
+    /// assert_eq!(pool.remaining(), 1);
+    /// ```
+    #[cfg(feature = "dice")]
+    pub fn spend<R: Rng>(&mut self, rng: &mut R, count: u8) -> Result<u32, InsufficientHitDice> {
+        if count > self.remaining() {
+            return Err(InsufficientHitDice {
+                requested: count,
+                available: self.remaining(),
+            });
+        }
+
+        self.spent += count;
+        Ok((0..count).map(|_| self.die.roll(rng)).sum())
+    }
+
+    /// Recovers up to `count` spent hit dice at the end of a long rest. No
+    /// more than half of [`Self::total`], rounded down but at least one, can
+    /// be recovered by a single rest.
+    pub fn recover(&mut self, count: u8) {
+        let limit = (self.total / 2).max(1);
+        self.spent -= count.min(limit).min(self.spent);
+    }
+}
+
+/// The error returned when spending more hit dice than a [`HitDicePool`] has
+/// remaining.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InsufficientHitDice {
+    requested: u8,
+    available: u8,
+}
+
+impl InsufficientHitDice {
+    /// Returns the number of hit dice that were requested.
+    #[must_use]
+    pub const fn requested(&self) -> u8 {
+        self.requested
+    }
+
+    /// Returns the number of hit dice that were available.
+    #[must_use]
+    pub const fn available(&self) -> u8 {
+        self.available
+    }
+}
+
+impl fmt::Display for InsufficientHitDice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cannot spend {} hit dice with only {} available",
+            self.requested, self.available
+        )
+    }
+}
+
+impl Error for InsufficientHitDice {}
+
+#[cfg(all(test, feature = "dice"))]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::HitDicePool;
+    use crate::Die;
+
+    #[test]
+    fn recover_is_limited_to_half_the_total() {
+        let mut rng = StdRng::seed_from_u64(121);
+
+        let mut pool = HitDicePool::new(Die::D10, 5);
+        pool.spend(&mut rng, 5).unwrap();
+        pool.recover(5);
+        assert_eq!(pool.remaining(), 2);
+        pool.recover(1);
+        assert_eq!(pool.remaining(), 3);
+        pool.recover(5);
+        pool.recover(5);
+        assert_eq!(pool.remaining(), 5);
+
+        let mut pool = HitDicePool::new(Die::D6, 1);
+        pool.spend(&mut rng, 1).unwrap();
+        pool.recover(1);
+        assert_eq!(pool.remaining(), 1);
+    }
+}
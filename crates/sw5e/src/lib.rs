@@ -4,6 +4,52 @@
 
 #![deny(clippy::pedantic, missing_docs)]
 
+mod ability;
+mod advantage;
+mod bonus;
+mod category;
+mod custom_tier;
+mod die;
+mod hit_dice;
+mod locale;
+mod marker;
+mod pack;
+mod power;
 mod proficency;
+mod rest;
+#[cfg(any(test, feature = "test-util"))]
+mod test_util;
+mod tier_map;
 
+pub use ability::AbilityScoreArray;
+#[cfg(feature = "dice")]
+pub use ability::{roll_4d6_drop_lowest, roll_ability_array};
+#[cfg(feature = "dice")]
+pub use advantage::resolve_advantage_detailed;
+pub use advantage::{
+    apply_reliable_talent,
+    crit_probability,
+    initiative_advantage,
+    should_reroll,
+    AdvantageState,
+};
+pub use bonus::{
+    check_bonus,
+    opposed_dc,
+    proficiency_bonus_for_cr,
+    proficiency_bonus_for_level,
+    BonusRule,
+};
+pub use category::{CategoryError, ProficiencyCategory, SkillProficiency, WeaponProficiency};
+pub use custom_tier::CustomTier;
+pub use die::Die;
+pub use hit_dice::{HitDicePool, InsufficientHitDice};
+pub use locale::Locale;
+pub use marker::TierMarker;
+pub use pack::{pack_tiers, unpack_tiers, DecodeError};
+pub use power::{InsufficientPoints, PointPool, Power, PowerType};
 pub use proficency::Proficency;
+pub use rest::Rest;
+#[cfg(any(test, feature = "test-util"))]
+pub use test_util::all_round_trip_vectors;
+pub use tier_map::TierMap;
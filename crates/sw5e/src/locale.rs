@@ -0,0 +1,51 @@
+/// This enum represents the languages that the crate's text is available in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Locale {
+    /// English, the language of the Player's Handbook.
+    #[default]
+    English,
+}
+
+/// The names of the proficency levels in English, ordered from `Untrained` to
+/// `GrandMastery`.
+const ENGLISH_PROFICENCY_NAMES: [&str; 7] = [
+    "Untrained",
+    "Trained",
+    "Proficient",
+    "Expertise",
+    "Mastery",
+    "High Mastery",
+    "Grand Mastery",
+];
+
+/// The rules text of the proficency levels in English, ordered from
+/// `Untrained` to `GrandMastery`.
+const ENGLISH_PROFICENCY_DESCRIPTIONS: [&str; 7] = [
+    "You add no proficiency bonus.",
+    "You add half your proficiency bonus, rounded down.",
+    "You add your proficiency bonus.",
+    "You add twice your proficiency bonus.",
+    "You add twice your proficiency bonus and always have advantage.",
+    "You add twice your proficiency bonus and always have advantage. When you roll with \
+     advantage, you can reroll one of the dice once, and you must use the new roll.",
+    "You add twice your proficiency bonus and always have advantage. When you roll with \
+     advantage, you can reroll each of the dice once, and you must use the new rolls.",
+];
+
+impl Locale {
+    /// Returns the names of the proficency levels in this locale, ordered from
+    /// `Untrained` to `GrandMastery`.
+    pub(crate) const fn proficency_names(self) -> &'static [&'static str; 7] {
+        match self {
+            Self::English => &ENGLISH_PROFICENCY_NAMES,
+        }
+    }
+
+    /// Returns the rules text of the proficency levels in this locale, ordered
+    /// from `Untrained` to `GrandMastery`.
+    pub(crate) const fn proficency_descriptions(self) -> &'static [&'static str; 7] {
+        match self {
+            Self::English => &ENGLISH_PROFICENCY_DESCRIPTIONS,
+        }
+    }
+}
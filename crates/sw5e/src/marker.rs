@@ -0,0 +1,85 @@
+use core::fmt;
+
+use crate::Proficency;
+
+/// This zero-sized struct represents a proficency level chosen at compile
+/// time, where `T` is the index passed to [`Proficency::from_u8`].
+///
+/// Creating a marker with an index that doesn't name a proficency level fails
+/// to compile.
+///
+/// ```compile_fail
+/// use sw5e::TierMarker;
+///
+/// let _ = TierMarker::<7>::new();
+/// ```
+///
+/// ```compile_fail
+/// use sw5e::TierMarker;
+///
+/// let _ = TierMarker::<7>::tier();
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TierMarker<const T: u8>(());
+
+impl<const T: u8> TierMarker<T> {
+    const TIER: Proficency = match Proficency::from_u8(T) {
+        Some(tier) => tier,
+        None => panic!("`TierMarker` index is not a proficency level"),
+    };
+
+    /// Creates a new marker.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::TierMarker;
+    ///
+    /// let marker = TierMarker::<2>::new();
+    /// assert_eq!(marker, TierMarker::default());
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        let _ = Self::TIER;
+        Self(())
+    }
+
+    /// Returns the proficency level this marker represents.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::{Proficency, TierMarker};
+    ///
+    /// assert_eq!(TierMarker::<0>::tier(), Proficency::Untrained);
+    /// assert_eq!(TierMarker::<2>::tier(), Proficency::Proficent);
+    /// ```
+    #[must_use]
+    pub const fn tier() -> Proficency {
+        Self::TIER
+    }
+}
+
+impl<const T: u8> Default for TierMarker<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Prints the marker's index alongside the proficency level it represents.
+///
+/// # Examples
+///
+/// ```
+/// use sw5e::TierMarker;
+///
+/// assert_eq!(
+///     format!("{:?}", TierMarker::<2>::new()),
+///     "TierMarker<2>(Proficient)"
+/// );
+/// ```
+impl<const T: u8> fmt::Debug for TierMarker<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "TierMarker<{T}>({:?})", Self::TIER)
+    }
+}
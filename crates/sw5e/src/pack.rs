@@ -0,0 +1,155 @@
+use core::fmt;
+use std::error::Error;
+
+use crate::Proficency;
+
+/// The number of bits each proficency level takes up when packed.
+const BITS_PER_TIER: usize = 3;
+
+/// Packs the given proficency levels into bytes, using three bits for each.
+///
+/// The level at position `i` is stored as its [`u8`
+/// index](Proficency::as_u8) in bits `3 * i` to `3 * i + 2` of the output,
+/// where bit `n` is bit `n % 8` of byte `n / 8`, counting from the least
+/// significant bit. Unused bits in the last byte are zero. The number of
+/// levels isn't stored, so it has to be passed to [`unpack_tiers`].
+///
+/// # Examples
+///
+/// ```
+/// use sw5e::pack_tiers;
+/// use sw5e::Proficency::*;
+///
+/// assert_eq!(pack_tiers(&[]), []);
+/// assert_eq!(pack_tiers(&[Proficent, Expertise, GrandMastery]), [
+///     0b1001_1010,
+///     0b1
+/// ]);
+/// ```
+#[must_use]
+pub fn pack_tiers(tiers: &[Proficency]) -> Vec<u8> {
+    let mut bytes = vec![0; packed_len(tiers.len())];
+
+    for (position, tier) in tiers.iter().enumerate() {
+        let bit = position * BITS_PER_TIER;
+        let [low, high] = (u16::from(tier.as_u8()) << (bit % 8)).to_le_bytes();
+
+        bytes[bit / 8] |= low;
+
+        if high != 0 {
+            bytes[bit / 8 + 1] |= high;
+        }
+    }
+
+    bytes
+}
+
+/// Unpacks `count` proficency levels from bytes produced by [`pack_tiers`].
+/// Bytes past the ones needed for `count` levels are ignored.
+///
+/// # Errors
+///
+/// Returns an error if `bytes` is too short to hold `count` levels, or if any
+/// of the packed values isn't a proficency level.
+///
+/// # Examples
+///
+/// ```
+/// use sw5e::{pack_tiers, unpack_tiers, DecodeError, Proficency};
+///
+/// let tiers: Vec<_> = (0..20).map(|i| Proficency::ALL[(i * 5 + 3) % 7]).collect();
+/// let bytes = pack_tiers(&tiers);
+/// assert_eq!(bytes.len(), 8);
+/// assert_eq!(unpack_tiers(&bytes, tiers.len()), Ok(tiers));
+///
+/// assert_eq!(
+///     unpack_tiers(&bytes[..7], 20),
+///     Err(DecodeError::Truncated {
+///         expected: 8,
+///         actual: 7
+///     }),
+/// );
+/// assert_eq!(unpack_tiers(&[0b111], 1), Err(DecodeError::InvalidTier(7)));
+/// assert!(matches!(
+///     unpack_tiers(&[], usize::MAX),
+///     Err(DecodeError::Truncated { actual: 0, .. }),
+/// ));
+/// ```
+pub fn unpack_tiers(bytes: &[u8], count: usize) -> Result<Vec<Proficency>, DecodeError> {
+    let expected = packed_len(count);
+
+    if bytes.len() < expected {
+        return Err(DecodeError::Truncated {
+            expected,
+            actual: bytes.len(),
+        });
+    }
+
+    (0..count)
+        .map(|position| {
+            let bit = position * BITS_PER_TIER;
+            let low = bytes[bit / 8];
+            let high = bytes.get(bit / 8 + 1).copied().unwrap_or_default();
+            let window = u16::from_le_bytes([low, high]) >> (bit % 8);
+            let index = window.to_le_bytes()[0] & 0b111;
+
+            Proficency::from_u8(index).ok_or(DecodeError::InvalidTier(index))
+        })
+        .collect()
+}
+
+/// Returns the number of bytes needed to pack `count` proficency levels,
+/// without overflowing for any `count`. Every 8 levels take exactly 3 bytes.
+const fn packed_len(count: usize) -> usize {
+    count / 8 * BITS_PER_TIER + (count % 8 * BITS_PER_TIER).div_ceil(8)
+}
+
+/// The error returned when decoding proficency levels fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecodeError {
+    /// The input ended before every value could be decoded.
+    Truncated {
+        /// The number of bytes that were needed.
+        expected: usize,
+        /// The number of bytes that were available.
+        actual: usize,
+    },
+    /// A decoded value isn't the index of a proficency level.
+    InvalidTier(u8),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Truncated { expected, actual } => {
+                write!(
+                    f,
+                    "expected {expected} bytes but only {actual} were available"
+                )
+            }
+            Self::InvalidTier(index) => write!(f, "{index} is not a proficency level"),
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{pack_tiers, unpack_tiers};
+    use crate::Proficency;
+
+    #[test]
+    fn round_trips_every_bit_offset() {
+        for len in 0..=16 {
+            let tiers: Vec<_> = (0..len)
+                .map(|i| Proficency::ALL[(i * 3 + len) % 7])
+                .collect();
+            assert_eq!(
+                unpack_tiers(&pack_tiers(&tiers), len),
+                Ok(tiers),
+                "length {len}"
+            );
+        }
+    }
+}
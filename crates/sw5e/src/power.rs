@@ -0,0 +1,256 @@
+use core::fmt;
+use std::error::Error;
+
+use crate::Rest;
+
+/// This enum represents the two kinds of powers in Star Wars 5e, which take
+/// the place of spells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PowerType {
+    /// A Force power, cast by spending force points.
+    Force,
+    /// A tech power, cast by spending tech points.
+    Tech,
+}
+
+/// This struct represents a Force or tech power.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Power {
+    name: &'static str,
+    level: u8,
+    kind: PowerType,
+    casting_time: &'static str,
+    range: &'static str,
+    point_cost: u32,
+}
+
+impl Power {
+    /// Creates a new power. A `level` of 0 means the power is an at-will
+    /// power. The `casting_time` and `range` are as written in the power's
+    /// description, such as `"1 action"` and `"30 feet"`.
+    #[must_use]
+    pub const fn new(
+        name: &'static str,
+        level: u8,
+        power_type: PowerType,
+        casting_time: &'static str,
+        range: &'static str,
+        point_cost: u32,
+    ) -> Self {
+        Self {
+            name,
+            level,
+            kind: power_type,
+            casting_time,
+            range,
+            point_cost,
+        }
+    }
+
+    /// Returns the name of the power.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the level of the power, or 0 if it is an at-will power.
+    #[must_use]
+    pub const fn level(&self) -> u8 {
+        self.level
+    }
+
+    /// Returns `true` if the power is an at-will power.
+    #[must_use]
+    pub const fn is_at_will(&self) -> bool {
+        self.level == 0
+    }
+
+    /// Returns whether the power is a Force or tech power.
+    #[must_use]
+    pub const fn power_type(&self) -> PowerType {
+        self.kind
+    }
+
+    /// Returns the time it takes to cast the power.
+    #[must_use]
+    pub const fn casting_time(&self) -> &'static str {
+        self.casting_time
+    }
+
+    /// Returns the range of the power.
+    #[must_use]
+    pub const fn range(&self) -> &'static str {
+        self.range
+    }
+
+    /// Returns the number of points it costs to cast the power. Force powers
+    /// cost force points and tech powers cost tech points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::{Power, PowerType};
+    ///
+    /// let power = Power::new("Saber Throw", 0, PowerType::Force, "1 action", "30 feet", 0);
+    /// assert!(power.is_at_will());
+    /// assert_eq!(power.point_cost(), 0);
+    /// ```
+    #[must_use]
+    pub const fn point_cost(&self) -> u32 {
+        self.point_cost
+    }
+
+    /// Returns an iterator over the powers of the given level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::{Power, PowerType};
+    ///
+    /// let powers = [
+    ///     Power::new("Saber Throw", 0, PowerType::Force, "1 action", "30 feet", 0),
+    ///     Power::new(
+    ///         "Force Push/Pull",
+    ///         1,
+    ///         PowerType::Force,
+    ///         "1 action",
+    ///         "60 feet",
+    ///         1,
+    ///     ),
+    ///     Power::new(
+    ///         "Encrypted Message",
+    ///         0,
+    ///         PowerType::Tech,
+    ///         "1 action",
+    ///         "120 feet",
+    ///         0,
+    ///     ),
+    /// ];
+    ///
+    /// let names: Vec<_> = Power::all_at_level(&powers, 0).map(Power::name).collect();
+    /// assert_eq!(names, ["Saber Throw", "Encrypted Message"]);
+    /// ```
+    pub fn all_at_level(powers: &[Self], level: u8) -> impl Iterator<Item = &Self> {
+        powers.iter().filter(move |power| power.level == level)
+    }
+}
+
+/// This struct tracks the force or tech points a character has available for
+/// casting powers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PointPool {
+    current: u32,
+    max: u32,
+}
+
+impl PointPool {
+    /// Creates a new, full point pool with the given maximum.
+    #[must_use]
+    pub const fn new(max: u32) -> Self {
+        Self { current: max, max }
+    }
+
+    /// Returns the number of points currently available.
+    #[must_use]
+    pub const fn current(&self) -> u32 {
+        self.current
+    }
+
+    /// Returns the maximum number of points in the pool.
+    #[must_use]
+    pub const fn max(&self) -> u32 {
+        self.max
+    }
+
+    /// Spends the given number of points.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, and leaves the pool unchanged, if fewer than `points`
+    /// points are available.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::PointPool;
+    ///
+    /// let mut pool = PointPool::new(5);
+    /// assert!(pool.spend(3).is_ok());
+    /// assert_eq!(pool.current(), 2);
+    /// assert!(pool.spend(3).is_err());
+    /// assert_eq!(pool.current(), 2);
+    /// ```
+    pub fn spend(&mut self, points: u32) -> Result<(), InsufficientPoints> {
+        match self.current.checked_sub(points) {
+            Some(current) => {
+                self.current = current;
+                Ok(())
+            }
+            None => {
+                Err(InsufficientPoints {
+                    requested: points,
+                    available: self.current,
+                })
+            }
+        }
+    }
+
+    /// Recovers points at the end of a rest. A long rest restores every point,
+    /// while a short rest restores half of the maximum, rounded down, without
+    /// going over the maximum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::{PointPool, Rest};
+    ///
+    /// let mut pool = PointPool::new(9);
+    /// pool.spend(9).unwrap();
+    ///
+    /// pool.recover_on_rest(Rest::Short);
+    /// assert_eq!(pool.current(), 4);
+    ///
+    /// pool.recover_on_rest(Rest::Long);
+    /// assert_eq!(pool.current(), 9);
+    /// ```
+    pub fn recover_on_rest(&mut self, rest: Rest) {
+        self.current = match rest {
+            Rest::Short => self.current.saturating_add(self.max / 2).min(self.max),
+            Rest::Long => self.max,
+        };
+    }
+}
+
+/// The error returned when spending more points than a [`PointPool`] has
+/// available.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InsufficientPoints {
+    requested: u32,
+    available: u32,
+}
+
+impl InsufficientPoints {
+    /// Returns the number of points that were requested.
+    #[must_use]
+    pub const fn requested(&self) -> u32 {
+        self.requested
+    }
+
+    /// Returns the number of points that were available.
+    #[must_use]
+    pub const fn available(&self) -> u32 {
+        self.available
+    }
+}
+
+impl fmt::Display for InsufficientPoints {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cannot spend {} points with only {} available",
+            self.requested, self.available
+        )
+    }
+}
+
+impl Error for InsufficientPoints {}
@@ -1,5 +1,18 @@
+use core::cmp::Ordering;
+use core::fmt;
+
+#[cfg(feature = "dice")]
+use rand::Rng;
+
+use crate::{AdvantageState, BonusRule, CategoryError, Locale, ProficiencyCategory};
+
 /// This enum represents the proficency levels in Star Wars 5e.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+///
+/// With the `serde` feature, each level is serialized by its variant name,
+/// always spelling `Proficent` as `"Proficient"`. Both `"Proficient"` and the
+/// legacy `"Proficent"` deserialize as `Proficent`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Proficency {
     /// The character is not proficent in the skill and has no bonus.
     #[default]
@@ -11,6 +24,7 @@ pub enum Proficency {
     /// The character is proficient in the skill and adds their full proficiency
     /// bonus. Proficency can only be obtained in skills, tools, saving throws,
     /// and weapons.
+    #[cfg_attr(feature = "serde", serde(rename = "Proficient", alias = "Proficent"))]
     Proficent,
     /// The character is an expert in the skill and adds twice their proficiency
     /// bonus. Expertise can only be obtained in skills, tools, and saving
@@ -36,6 +50,114 @@ pub enum Proficency {
 }
 
 impl Proficency {
+    /// The lowest proficency level whose rolls are always made with advantage.
+    pub const ADVANTAGE_THRESHOLD: Self = Self::Mastery;
+    /// Every proficency level, ordered from `Untrained` to `GrandMastery`.
+    pub const ALL: [Self; 7] = [
+        Self::Untrained,
+        Self::Trained,
+        Self::Proficent,
+        Self::Expertise,
+        Self::Mastery,
+        Self::HighMastery,
+        Self::GrandMastery,
+    ];
+    /// The lowest proficency level that can reroll dice when rolling with
+    /// advantage.
+    pub const HIGH_MASTERY_REROLL_THRESHOLD: Self = Self::HighMastery;
+
+    /// Returns the bonus this proficency level adds at the given level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::Proficency::*;
+    ///
+    /// assert_eq!(Untrained.bonus(5), 0);
+    /// assert_eq!(Trained.bonus(5), 1);
+    /// assert_eq!(Proficent.bonus(5), 3);
+    /// assert_eq!(Expertise.bonus(5), 6);
+    /// assert_eq!(GrandMastery.bonus(5), 6);
+    /// ```
+    #[must_use]
+    pub const fn bonus(self, level: u8) -> i32 {
+        self.bonus_with_rule(BonusRule::ByLevel, level)
+    }
+
+    /// Returns the bonus this proficency level adds at the given level, using
+    /// the given rule to determine the base proficiency bonus. The result
+    /// saturates at the bounds of `i32` for extreme flat bonuses.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::BonusRule;
+    /// use sw5e::Proficency::*;
+    ///
+    /// assert_eq!(Expertise.bonus_with_rule(BonusRule::Flat(3), 20), 6);
+    /// assert_eq!(Trained.bonus_with_rule(BonusRule::Flat(3), 1), 1);
+    /// assert_eq!(Trained.bonus_with_rule(BonusRule::Flat(-3), 1), -2);
+    /// assert_eq!(
+    ///     Expertise.bonus_with_rule(BonusRule::Flat(i32::MAX), 1),
+    ///     i32::MAX
+    /// );
+    /// assert_eq!(
+    ///     Expertise.bonus_with_rule(BonusRule::Flat(i32::MIN), 1),
+    ///     i32::MIN
+    /// );
+    /// assert_eq!(
+    ///     Proficent.bonus_with_rule(BonusRule::ByLevel, 9),
+    ///     Proficent.bonus(9),
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn bonus_with_rule(self, rule: BonusRule, level: u8) -> i32 {
+        let base = rule.base_bonus(level);
+        let doubled_scale = self.doubled_scale();
+
+        // Splitting the base into halves keeps the result exact while letting
+        // the multiplication saturate for extreme flat bonuses.
+        base.div_euclid(2)
+            .saturating_mul(doubled_scale)
+            .saturating_add((base.rem_euclid(2) * doubled_scale).div_euclid(2))
+    }
+
+    /// Returns twice the multiplier applied to the proficiency bonus at this
+    /// proficency level, so that half-proficiency stays an integer.
+    pub(crate) const fn doubled_scale(self) -> i32 {
+        match self {
+            Self::Untrained => 0,
+            Self::Trained => 1,
+            Self::Proficent => 2,
+            Self::Expertise | Self::Mastery | Self::HighMastery | Self::GrandMastery => 4,
+        }
+    }
+
+    /// Returns the name of the proficency level in the given locale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::Locale;
+    /// use sw5e::Proficency::*;
+    ///
+    /// for tier in [
+    ///     Untrained,
+    ///     Trained,
+    ///     Proficent,
+    ///     Expertise,
+    ///     Mastery,
+    ///     HighMastery,
+    ///     GrandMastery,
+    /// ] {
+    ///     assert_eq!(tier.name_in(Locale::English), tier.to_string());
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn name_in(self, locale: Locale) -> &'static str {
+        locale.proficency_names()[self as usize]
+    }
+
     /// Returns the next proficency level, or `None` if the current level is
     /// `GrandMastery`.
     ///
@@ -151,4 +273,835 @@ impl Proficency {
             Self::GrandMastery => Self::HighMastery,
         }
     }
+
+    /// Returns the next proficency level in the given category that changes
+    /// the bonus, or `None` if no higher level in the category does. Levels
+    /// that only add advantage or rerolls are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::Proficency::*;
+    /// use sw5e::ProficiencyCategory;
+    ///
+    /// let skill = ProficiencyCategory::Skill;
+    /// assert_eq!(Untrained.next_bonus_changing_tier(skill), Some(Trained));
+    /// assert_eq!(Proficent.next_bonus_changing_tier(skill), Some(Expertise));
+    /// assert_eq!(Expertise.next_bonus_changing_tier(skill), None);
+    /// assert_eq!(Mastery.next_bonus_changing_tier(skill), None);
+    ///
+    /// let weapon = ProficiencyCategory::Weapon;
+    /// assert_eq!(Trained.next_bonus_changing_tier(weapon), Some(Proficent));
+    /// assert_eq!(Proficent.next_bonus_changing_tier(weapon), None);
+    /// ```
+    #[must_use]
+    pub const fn next_bonus_changing_tier(self, category: ProficiencyCategory) -> Option<Self> {
+        let max = category.max_tier() as u8;
+        let mut tier = self;
+
+        while let Some(next) = tier.increase() {
+            if next as u8 > max {
+                return None;
+            }
+
+            if next.doubled_scale() != self.doubled_scale() {
+                return Some(next);
+            }
+
+            tier = next;
+        }
+
+        None
+    }
+
+    /// Returns `true` if rolls at this proficency level are always made with
+    /// advantage, which is the case for [`Self::ADVANTAGE_THRESHOLD`] and
+    /// above.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::Proficency;
+    /// use sw5e::Proficency::*;
+    ///
+    /// assert_eq!(Proficency::ADVANTAGE_THRESHOLD, Mastery);
+    /// assert!(!Untrained.has_advantage());
+    /// assert!(!Expertise.has_advantage());
+    /// assert!(Mastery.has_advantage());
+    /// assert!(HighMastery.has_advantage());
+    /// assert!(GrandMastery.has_advantage());
+    /// ```
+    #[must_use]
+    pub const fn has_advantage(self) -> bool {
+        self as u8 >= Self::ADVANTAGE_THRESHOLD as u8
+    }
+
+    /// Returns the state of a roll at this proficency level, given whether
+    /// other sources grant advantage or impose disadvantage on it.
+    ///
+    /// The advantage granted by `Mastery` and above is one more source of
+    /// advantage, so it cancels against any disadvantage just like external
+    /// advantage does. Sources never stack, so a mastery roll with external
+    /// advantage is still made with advantage, and a single source of
+    /// disadvantage is enough to cancel both down to a normal roll.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::AdvantageState;
+    /// use sw5e::Proficency::*;
+    ///
+    /// assert_eq!(
+    ///     Mastery.net_advantage(false, false),
+    ///     AdvantageState::Advantage
+    /// );
+    /// assert_eq!(Mastery.net_advantage(false, true), AdvantageState::Normal);
+    /// assert_eq!(Mastery.net_advantage(true, true), AdvantageState::Normal);
+    /// assert_eq!(
+    ///     Expertise.net_advantage(false, true),
+    ///     AdvantageState::Disadvantage
+    /// );
+    /// assert_eq!(
+    ///     Expertise.net_advantage(true, false),
+    ///     AdvantageState::Advantage
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn net_advantage(
+        self,
+        external_advantage: bool,
+        external_disadvantage: bool,
+    ) -> AdvantageState {
+        AdvantageState::from_sources(
+            self.has_advantage() || external_advantage,
+            external_disadvantage,
+        )
+    }
+
+    /// Returns the ordering of every pair of proficency levels, as
+    /// `(left, right, left.cmp(&right))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::Proficency;
+    ///
+    /// let pairs = Proficency::compare_all();
+    /// assert_eq!(pairs.len(), 49);
+    ///
+    /// for (left, right, ordering) in pairs {
+    ///     assert_eq!(ordering, (left as u8).cmp(&(right as u8)));
+    /// }
+    /// ```
+    #[must_use]
+    pub fn compare_all() -> Vec<(Self, Self, Ordering)> {
+        Self::ALL
+            .iter()
+            .flat_map(|&left| {
+                Self::ALL
+                    .iter()
+                    .map(move |&right| (left, right, left.cmp(&right)))
+            })
+            .collect()
+    }
+
+    /// Returns the proficency level with the given index, counting from 0 for
+    /// `Untrained` to 6 for `GrandMastery`, or `None` if the index is out of
+    /// range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::Proficency;
+    /// use sw5e::Proficency::*;
+    ///
+    /// assert_eq!(Proficency::from_u8(0), Some(Untrained));
+    /// assert_eq!(Proficency::from_u8(2), Some(Proficent));
+    /// assert_eq!(Proficency::from_u8(6), Some(GrandMastery));
+    /// assert_eq!(Proficency::from_u8(7), None);
+    /// ```
+    #[must_use]
+    pub const fn from_u8(index: u8) -> Option<Self> {
+        if (index as usize) < Self::ALL.len() {
+            Some(Self::ALL[index as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Returns how far this proficency level is along the way from `Untrained`
+    /// to `GrandMastery`, from 0.0 to 1.0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::Proficency::*;
+    ///
+    /// assert_eq!(Untrained.progress(), 0.0);
+    /// assert!((Proficent.progress() - 1.0 / 3.0).abs() < f32::EPSILON);
+    /// assert_eq!(GrandMastery.progress(), 1.0);
+    /// ```
+    #[must_use]
+    pub const fn progress(self) -> f32 {
+        self as u8 as f32 / Self::GrandMastery as u8 as f32
+    }
+
+    /// Returns the position of this proficency level, counting from 1 for
+    /// `Untrained` to 7 for `GrandMastery`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::Proficency::*;
+    ///
+    /// assert_eq!(Untrained.ordinal(), 1);
+    /// assert_eq!(Proficent.ordinal(), 3);
+    /// assert_eq!(GrandMastery.ordinal(), 7);
+    /// ```
+    #[must_use]
+    pub const fn ordinal(self) -> u8 {
+        self as u8 + 1
+    }
+
+    /// Returns `true` if this proficency level can be obtained in the given
+    /// category.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::Proficency::*;
+    /// use sw5e::ProficiencyCategory;
+    ///
+    /// assert!(Proficent.can_be_applied_to(ProficiencyCategory::Weapon));
+    /// assert!(!Expertise.can_be_applied_to(ProficiencyCategory::Weapon));
+    /// assert!(GrandMastery.can_be_applied_to(ProficiencyCategory::Skill));
+    /// ```
+    #[must_use]
+    pub const fn can_be_applied_to(self, category: ProficiencyCategory) -> bool {
+        self as u8 <= category.max_tier() as u8
+    }
+
+    /// Returns `true` if training can be started in the given category at
+    /// all, which is the case for every category.
+    ///
+    /// This is distinct from [`Self::can_be_applied_to`], which asks whether a
+    /// particular proficency level is legal in a category. Weapons accept
+    /// training even though `Expertise` and above can't be applied to them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::Proficency::*;
+    /// use sw5e::{Proficency, ProficiencyCategory};
+    ///
+    /// let weapon = ProficiencyCategory::Weapon;
+    /// assert!(Proficency::is_trainable_in(weapon));
+    /// assert!(Trained.can_be_applied_to(weapon));
+    /// assert!(!Mastery.can_be_applied_to(weapon));
+    /// ```
+    #[must_use]
+    pub const fn is_trainable_in(category: ProficiencyCategory) -> bool {
+        match category {
+            ProficiencyCategory::Skill
+            | ProficiencyCategory::Tool
+            | ProficiencyCategory::SavingThrow
+            | ProficiencyCategory::Weapon => true,
+        }
+    }
+
+    /// Returns how many proficency levels this level is below `required`, or 0
+    /// if it already meets it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::Proficency::*;
+    ///
+    /// assert_eq!(Trained.shortfall_to(Expertise), 2);
+    /// assert_eq!(Expertise.shortfall_to(Expertise), 0);
+    /// assert_eq!(GrandMastery.shortfall_to(Trained), 0);
+    /// ```
+    #[must_use]
+    pub const fn shortfall_to(self, required: Self) -> u8 {
+        (required as u8).saturating_sub(self as u8)
+    }
+
+    /// Returns the abbreviation of the proficency level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::Proficency::*;
+    ///
+    /// assert_eq!(Untrained.abbreviation(), "U");
+    /// assert_eq!(Proficent.abbreviation(), "P");
+    /// assert_eq!(HighMastery.abbreviation(), "HM");
+    /// assert_eq!(GrandMastery.abbreviation(), "GM");
+    /// ```
+    #[must_use]
+    pub const fn abbreviation(self) -> &'static str {
+        match self {
+            Self::Untrained => "U",
+            Self::Trained => "T",
+            Self::Proficent => "P",
+            Self::Expertise => "E",
+            Self::Mastery => "M",
+            Self::HighMastery => "HM",
+            Self::GrandMastery => "GM",
+        }
+    }
+
+    /// Returns a table of every proficency level with its abbreviation and its
+    /// bonus at the given level, with one row per line and columns aligned for
+    /// a monospace font.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::Proficency;
+    ///
+    /// let table = Proficency::tier_table(5);
+    /// assert_eq!(table.lines().count(), 8);
+    /// assert!(table
+    ///     .lines()
+    ///     .any(|row| row == "Grand Mastery  GM        +6"));
+    /// ```
+    #[must_use]
+    pub fn tier_table(level: u8) -> String {
+        let header = format!("{:<13}  {:<5}  {:>5}\n", "Name", "Abbr.", "Bonus");
+        let rows = Self::ALL.iter().map(|tier| {
+            format!(
+                "{:<13}  {:<5}  {:>+5}\n",
+                tier.name_in(Locale::English),
+                tier.abbreviation(),
+                tier.bonus(level),
+            )
+        });
+
+        std::iter::once(header).chain(rows).collect()
+    }
+
+    /// Returns the highest of the given proficency levels, or `Untrained` if
+    /// there are none. A character with the same proficency from several
+    /// sources only benefits from the highest one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::Proficency;
+    /// use sw5e::Proficency::*;
+    ///
+    /// assert_eq!(
+    ///     Proficency::combine_all([Trained, Untrained, Expertise, Proficent]),
+    ///     Expertise,
+    /// );
+    /// assert_eq!(Proficency::combine_all([]), Untrained);
+    /// ```
+    #[must_use]
+    pub fn combine_all<I: IntoIterator<Item = Self>>(sources: I) -> Self {
+        sources.into_iter().max().unwrap_or_default()
+    }
+
+    /// Returns `true` if `query` is a prefix of the English name or the
+    /// abbreviation of the proficency level, ignoring case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::Proficency::*;
+    ///
+    /// assert!(Mastery.matches_name("mas"));
+    /// assert!(!Mastery.matches_name("gr"));
+    /// assert!(GrandMastery.matches_name("gr"));
+    /// assert!(HighMastery.matches_name("hm"));
+    /// ```
+    #[must_use]
+    pub fn matches_name(self, query: &str) -> bool {
+        let is_prefix = |text: &str| {
+            text.get(..query.len())
+                .is_some_and(|prefix| prefix.eq_ignore_ascii_case(query))
+        };
+
+        is_prefix(self.name_in(Locale::English)) || is_prefix(self.abbreviation())
+    }
+
+    /// Returns the index of this proficency level, counting from 0 for
+    /// `Untrained` to 6 for `GrandMastery`. This is the inverse of
+    /// [`Self::from_u8`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::Proficency;
+    ///
+    /// for tier in Proficency::ALL {
+    ///     assert_eq!(Proficency::from_u8(tier.as_u8()), Some(tier));
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn as_u8(self) -> u8 {
+        self as u8
+    }
+
+    /// Returns the lowest proficency level that can be obtained in the given
+    /// category whose bonus at the given level is at least `target`, or `None`
+    /// if no such level exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::Proficency::*;
+    /// use sw5e::{Proficency, ProficiencyCategory};
+    ///
+    /// let skill = ProficiencyCategory::Skill;
+    /// assert_eq!(
+    ///     Proficency::minimum_tier_for_bonus(0, 1, skill),
+    ///     Some(Untrained)
+    /// );
+    /// assert_eq!(
+    ///     Proficency::minimum_tier_for_bonus(2, 1, skill),
+    ///     Some(Proficent)
+    /// );
+    /// assert_eq!(
+    ///     Proficency::minimum_tier_for_bonus(4, 1, skill),
+    ///     Some(Expertise)
+    /// );
+    /// assert_eq!(Proficency::minimum_tier_for_bonus(5, 1, skill), None);
+    ///
+    /// let weapon = ProficiencyCategory::Weapon;
+    /// assert_eq!(
+    ///     Proficency::minimum_tier_for_bonus(2, 1, weapon),
+    ///     Some(Proficent)
+    /// );
+    /// assert_eq!(Proficency::minimum_tier_for_bonus(4, 1, weapon), None);
+    /// ```
+    #[must_use]
+    pub fn minimum_tier_for_bonus(
+        target: i32,
+        level: u8,
+        category: ProficiencyCategory,
+    ) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .filter(|tier| tier.can_be_applied_to(category))
+            .find(|tier| tier.bonus(level) >= target)
+    }
+
+    /// Returns how many of the dice in a roll with advantage can be rerolled
+    /// at this proficency level: none below
+    /// [`Self::HIGH_MASTERY_REROLL_THRESHOLD`], one at `HighMastery`, and both
+    /// at `GrandMastery`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::Proficency;
+    /// use sw5e::Proficency::*;
+    ///
+    /// assert_eq!(Proficency::HIGH_MASTERY_REROLL_THRESHOLD, HighMastery);
+    /// assert_eq!(Mastery.reroll_dice_count(), 0);
+    /// assert_eq!(HighMastery.reroll_dice_count(), 1);
+    /// assert_eq!(GrandMastery.reroll_dice_count(), 2);
+    /// ```
+    #[must_use]
+    pub const fn reroll_dice_count(self) -> u8 {
+        match self {
+            Self::GrandMastery => 2,
+            _ if self as u8 >= Self::HIGH_MASTERY_REROLL_THRESHOLD as u8 => 1,
+            _ => 0,
+        }
+    }
+
+    /// Parses a proficency level from loosely formatted text, such as text
+    /// typed by a user. Everything but ASCII letters and digits is ignored and
+    /// case doesn't matter, so `" Grand-Mastery! "` parses as `GrandMastery`.
+    /// Besides the English names, `"expert"` is accepted for `Expertise` and
+    /// the misspelling `"proficent"` for `Proficent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::Proficency;
+    /// use sw5e::Proficency::*;
+    ///
+    /// assert_eq!(Proficency::parse_lenient("proficient!"), Some(Proficent));
+    /// assert_eq!(Proficency::parse_lenient(" EXPERT "), Some(Expertise));
+    /// assert_eq!(
+    ///     Proficency::parse_lenient("grand mastery"),
+    ///     Some(GrandMastery)
+    /// );
+    /// assert_eq!(Proficency::parse_lenient("High_Mastery"), Some(HighMastery));
+    /// assert_eq!(Proficency::parse_lenient("legendary"), None);
+    /// assert_eq!(Proficency::parse_lenient(""), None);
+    /// ```
+    #[must_use]
+    pub fn parse_lenient(s: &str) -> Option<Self> {
+        let key: String = s
+            .chars()
+            .filter(char::is_ascii_alphanumeric)
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+
+        match key.as_str() {
+            "untrained" => Some(Self::Untrained),
+            "trained" => Some(Self::Trained),
+            "proficient" | "proficent" => Some(Self::Proficent),
+            "expertise" | "expert" => Some(Self::Expertise),
+            "mastery" => Some(Self::Mastery),
+            "highmastery" => Some(Self::HighMastery),
+            "grandmastery" => Some(Self::GrandMastery),
+            _ => None,
+        }
+    }
+
+    /// Returns the rules text of the proficency level in English.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::Proficency::*;
+    ///
+    /// assert_eq!(
+    ///     Expertise.describe(),
+    ///     "You add twice your proficiency bonus."
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn describe(self) -> &'static str {
+        self.describe_in(Locale::English)
+    }
+
+    /// Returns the rules text of the proficency level in the given locale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::{Locale, Proficency};
+    ///
+    /// for tier in Proficency::ALL {
+    ///     assert_eq!(tier.describe_in(Locale::English), tier.describe());
+    /// }
+    ///
+    /// let text = Proficency::HighMastery.describe_in(Locale::English);
+    /// assert!(text.contains("reroll one of the dice"));
+    /// ```
+    #[must_use]
+    pub const fn describe_in(self, locale: Locale) -> &'static str {
+        locale.proficency_descriptions()[self as usize]
+    }
+
+    /// Returns the part of a check's bonus that comes from this proficency
+    /// level at the given level, leaving out the ability modifier. This is the
+    /// same as [`Self::bonus`], and [`check_bonus`](crate::check_bonus) is the
+    /// ability modifier plus this value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::{check_bonus, Proficency};
+    ///
+    /// let tier = Proficency::Expertise;
+    /// assert_eq!(tier.proficiency_contribution(9), tier.bonus(9));
+    /// assert_eq!(
+    ///     check_bonus(3, tier, 9),
+    ///     3 + tier.proficiency_contribution(9)
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn proficiency_contribution(self, level: u8) -> i32 {
+        self.bonus(level)
+    }
+
+    /// Returns every proficency level passed through when raising `from` to
+    /// `to` one level at a time, ending with `to`. The path is empty if `from`
+    /// is already at or above `to`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `to` can't be obtained in the given category.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::Proficency::*;
+    /// use sw5e::{Proficency, ProficiencyCategory};
+    ///
+    /// assert_eq!(
+    ///     Proficency::upgrade_path(Untrained, Expertise, ProficiencyCategory::Skill),
+    ///     Ok(vec![Trained, Proficent, Expertise]),
+    /// );
+    /// assert_eq!(
+    ///     Proficency::upgrade_path(Expertise, Trained, ProficiencyCategory::Skill),
+    ///     Ok(vec![]),
+    /// );
+    /// assert!(Proficency::upgrade_path(Untrained, Expertise, ProficiencyCategory::Weapon).is_err());
+    /// ```
+    pub fn upgrade_path(
+        from: Self,
+        to: Self,
+        category: ProficiencyCategory,
+    ) -> Result<Vec<Self>, CategoryError> {
+        if !to.can_be_applied_to(category) {
+            return Err(CategoryError::new(to, category));
+        }
+
+        Ok(Self::ALL[from as usize + 1..]
+            .iter()
+            .copied()
+            .take_while(|&tier| tier <= to)
+            .collect())
+    }
+
+    /// Returns the multiplier applied to the proficiency bonus at this
+    /// proficency level: 0.0, 0.5, 1.0, or 2.0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::Proficency::*;
+    ///
+    /// assert_eq!(Untrained.scale_f64(), 0.0);
+    /// assert_eq!(Trained.scale_f64(), 0.5);
+    /// assert_eq!(Proficent.scale_f64(), 1.0);
+    /// assert_eq!(GrandMastery.scale_f64(), 2.0);
+    /// ```
+    #[must_use]
+    pub const fn scale_f64(self) -> f64 {
+        self.doubled_scale() as f64 / 2.0
+    }
+
+    /// Returns the lowest proficency level with the given
+    /// [multiplier](Self::scale_f64), or `None` if no level has it. Since
+    /// `Expertise` and every level above it share a multiplier of 2.0, 2.0
+    /// always maps back to `Expertise`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::Proficency;
+    /// use sw5e::Proficency::*;
+    ///
+    /// for tier in [Untrained, Trained, Proficent, Expertise] {
+    ///     assert_eq!(Proficency::from_scale_f64(tier.scale_f64()), Some(tier));
+    /// }
+    ///
+    /// assert_eq!(
+    ///     Proficency::from_scale_f64(GrandMastery.scale_f64()),
+    ///     Some(Expertise)
+    /// );
+    /// assert_eq!(Proficency::from_scale_f64(-0.0), Some(Untrained));
+    /// assert_eq!(Proficency::from_scale_f64(1.5), None);
+    /// ```
+    #[must_use]
+    pub const fn from_scale_f64(scale: f64) -> Option<Self> {
+        // Adding 0.0 turns -0.0 into 0.0, so that their bits compare equal.
+        let scale = scale + 0.0;
+        let mut index = 0;
+
+        while index < Self::ALL.len() {
+            let tier = Self::ALL[index];
+
+            if tier.scale_f64().to_bits() == scale.to_bits() {
+                return Some(tier);
+            }
+
+            index += 1;
+        }
+
+        None
+    }
+
+    /// Compares this proficency level with the one named by `name`, which is
+    /// parsed with [`Self::parse_lenient`]. Returns `None` if `name` doesn't
+    /// name a proficency level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    ///
+    /// use sw5e::Proficency::*;
+    ///
+    /// assert_eq!(Mastery.cmp_name("Expertise"), Some(Ordering::Greater));
+    /// assert_eq!(Proficent.cmp_name("proficient"), Some(Ordering::Equal));
+    /// assert_eq!(Trained.cmp_name("grand mastery"), Some(Ordering::Less));
+    /// assert_eq!(Trained.cmp_name("legendary"), None);
+    /// ```
+    #[must_use]
+    pub fn cmp_name(self, name: &str) -> Option<Ordering> {
+        Self::parse_lenient(name).map(|other| self.cmp(&other))
+    }
+
+    /// Returns `true` if this proficency level adds twice the proficiency
+    /// bonus, which is the case for `Expertise` and above. This is separate
+    /// from [`Self::has_advantage`], which starts at `Mastery`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::Proficency::*;
+    ///
+    /// assert!(!Proficent.doubles_bonus());
+    /// assert!(Expertise.doubles_bonus());
+    /// assert!(!Expertise.has_advantage());
+    /// assert!(Mastery.doubles_bonus());
+    /// assert!(Mastery.has_advantage());
+    /// ```
+    #[must_use]
+    pub const fn doubles_bonus(self) -> bool {
+        self as u8 >= Self::Expertise as u8
+    }
+
+    /// Returns a Markdown snippet with the English name of the proficency
+    /// level in bold, followed by its bonus at the given level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::Proficency::*;
+    ///
+    /// assert_eq!(Proficent.to_markdown(5), "**Proficient** (+3)");
+    /// assert_eq!(GrandMastery.to_markdown(1), "**Grand Mastery** (+4)");
+    /// ```
+    #[must_use]
+    pub fn to_markdown(self, level: u8) -> String {
+        format!("**{self}** ({:+})", self.bonus(level))
+    }
+
+    /// Returns an iterator over this proficency level and every level below
+    /// it, from highest to lowest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::Proficency::*;
+    ///
+    /// assert_eq!(Proficent.iter_down_from().collect::<Vec<_>>(), [
+    ///     Proficent, Trained, Untrained
+    /// ]);
+    /// assert_eq!(Untrained.iter_down_from().collect::<Vec<_>>(), [Untrained]);
+    /// assert_eq!(GrandMastery.iter_down_from().count(), 7);
+    /// ```
+    pub fn iter_down_from(self) -> impl Iterator<Item = Self> {
+        core::iter::successors(Some(self), |tier| tier.decrease())
+    }
+
+    /// Returns a random proficency level, where each level is chosen with a
+    /// chance proportional to its weight. The weights are indexed like
+    /// [`Self::ALL`]. If every weight is zero, `Untrained` is returned.
+    ///
+    /// This function is only available with the `dice` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    /// use sw5e::Proficency;
+    ///
+    /// let mut rng = StdRng::seed_from_u64(118);
+    /// let only_expertise = [0, 0, 0, 1, 0, 0, 0];
+    /// for _ in 0..100 {
+    ///     let tier = Proficency::weighted_sample(&mut rng, only_expertise);
+    ///     assert_eq!(tier, Proficency::Expertise);
+    /// }
+    ///
+    /// let tier = Proficency::weighted_sample(&mut rng, [0; 7]);
+    /// assert_eq!(tier, Proficency::Untrained);
+    /// ```
+    #[cfg(feature = "dice")]
+    pub fn weighted_sample<R: Rng>(rng: &mut R, weights: [u32; 7]) -> Self {
+        let total: u64 = weights.into_iter().map(u64::from).sum();
+        if total == 0 {
+            return Self::Untrained;
+        }
+
+        let mut pick = rng.gen_range(0..total);
+        for (tier, weight) in Self::ALL.into_iter().zip(weights) {
+            match pick.checked_sub(u64::from(weight)) {
+                Some(rest) => pick = rest,
+                None => return tier,
+            }
+        }
+        unreachable!("the pick is less than the total weight")
+    }
+}
+
+/// Formats the proficency level by its English name. The alternate flag
+/// prefixes the name with its [ordinal](Proficency::ordinal). Width, fill, and
+/// alignment are respected.
+///
+/// # Examples
+///
+/// ```
+/// use sw5e::Proficency;
+///
+/// assert_eq!(format!("{}", Proficency::Proficent), "Proficient");
+/// assert_eq!(format!("{:#}", Proficency::Proficent), "3. Proficient");
+/// assert_eq!(format!("{:<12}|", Proficency::Mastery), "Mastery     |");
+/// assert_eq!(format!("{:>12}|", Proficency::Mastery), "     Mastery|");
+/// assert_eq!(format!("{:*^11}", Proficency::Mastery), "**Mastery**");
+/// assert_eq!(format!("{:>#14}", Proficency::Proficent), " 3. Proficient");
+/// ```
+impl fmt::Display for Proficency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = self.name_in(Locale::English);
+
+        if f.alternate() {
+            f.pad(&format!("{}. {name}", self.ordinal()))
+        } else {
+            f.pad(name)
+        }
+    }
+}
+
+/// Formats the proficency level by its variant name, spelling `Proficent` as
+/// `Proficient`.
+///
+/// # Examples
+///
+/// ```
+/// use sw5e::Proficency;
+///
+/// assert_eq!(format!("{:?}", Proficency::Proficent), "Proficient");
+/// assert_eq!(format!("{:?}", Proficency::HighMastery), "HighMastery");
+/// assert_eq!(
+///     format!("{:?}", Some(Proficency::Proficent)),
+///     "Some(Proficient)"
+/// );
+/// ```
+impl fmt::Debug for Proficency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Untrained => "Untrained",
+            Self::Trained => "Trained",
+            Self::Proficent => "Proficient",
+            Self::Expertise => "Expertise",
+            Self::Mastery => "Mastery",
+            Self::HighMastery => "HighMastery",
+            Self::GrandMastery => "GrandMastery",
+        })
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use serde::de::value::{Error, StrDeserializer};
+    use serde::de::IntoDeserializer;
+    use serde::Deserialize;
+
+    use super::Proficency;
+
+    fn deserialize(name: &str) -> Result<Proficency, Error> {
+        let deserializer: StrDeserializer<'_, Error> = name.into_deserializer();
+        Proficency::deserialize(deserializer)
+    }
+
+    #[test]
+    fn deserialize_accepts_both_spellings_of_proficient() {
+        assert_eq!(deserialize("Proficient").unwrap(), Proficency::Proficent);
+        assert_eq!(deserialize("Proficent").unwrap(), Proficency::Proficent);
+        assert_eq!(deserialize("HighMastery").unwrap(), Proficency::HighMastery);
+        assert!(deserialize("Expert").is_err());
+    }
 }
@@ -0,0 +1,8 @@
+/// This enum represents the two kinds of rest a character can take.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Rest {
+    /// A short rest of at least an hour.
+    Short,
+    /// A long rest of at least eight hours.
+    Long,
+}
@@ -0,0 +1,41 @@
+use crate::{Locale, Proficency};
+
+/// Returns every proficency level paired with its [`u8`
+/// index](Proficency::as_u8) and its English name, for use in downstream
+/// serialization tests.
+///
+/// This function is only available with the `test-util` feature.
+///
+/// # Examples
+///
+/// ```
+/// use sw5e::{all_round_trip_vectors, Proficency};
+///
+/// let vectors = all_round_trip_vectors();
+/// assert_eq!(vectors[2], (Proficency::Proficent, 2, "Proficient"));
+/// ```
+#[must_use]
+pub fn all_round_trip_vectors() -> Vec<(Proficency, u8, &'static str)> {
+    Proficency::ALL
+        .into_iter()
+        .map(|tier| (tier, tier.as_u8(), tier.name_in(Locale::English)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::all_round_trip_vectors;
+    use crate::Proficency;
+
+    #[test]
+    fn round_trip_vectors_match_as_u8_and_display() {
+        let vectors = all_round_trip_vectors();
+        assert_eq!(vectors.len(), 7);
+
+        for (tier, index, name) in vectors {
+            assert_eq!(tier.as_u8(), index);
+            assert_eq!(Proficency::from_u8(index), Some(tier));
+            assert_eq!(tier.to_string(), name);
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use crate::Proficency;
+
+/// This struct maps every proficency level to a value of type `T`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct TierMap<T>([T; 7]);
+
+impl<T> TierMap<T> {
+    /// Creates a new map by calling `f` with every proficency level, from
+    /// `Untrained` to `GrandMastery`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::{Proficency, TierMap};
+    ///
+    /// let bonuses = TierMap::from_fn(|tier| tier.bonus(1));
+    /// assert_eq!(*bonuses.get(Proficency::Proficent), 2);
+    /// assert_eq!(*bonuses.get(Proficency::Expertise), 4);
+    /// ```
+    pub fn from_fn<F: FnMut(Proficency) -> T>(mut f: F) -> Self {
+        Self(core::array::from_fn(|index| f(Proficency::ALL[index])))
+    }
+
+    /// Returns a reference to the value of the given proficency level.
+    #[must_use]
+    pub const fn get(&self, tier: Proficency) -> &T {
+        &self.0[tier as usize]
+    }
+
+    /// Returns a mutable reference to the value of the given proficency level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::{Proficency, TierMap};
+    ///
+    /// let mut counts = TierMap::<u32>::default();
+    /// *counts.get_mut(Proficency::Mastery) += 1;
+    /// assert_eq!(*counts.get(Proficency::Mastery), 1);
+    /// ```
+    pub fn get_mut(&mut self, tier: Proficency) -> &mut T {
+        &mut self.0[tier as usize]
+    }
+
+    /// Returns an iterator over every proficency level and its value, from
+    /// `Untrained` to `GrandMastery`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sw5e::{Proficency, TierMap};
+    ///
+    /// let ordinals = TierMap::from_fn(Proficency::ordinal);
+    /// assert!(ordinals
+    ///     .iter()
+    ///     .all(|(tier, &ordinal)| tier.ordinal() == ordinal));
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (Proficency, &T)> {
+        Proficency::ALL.into_iter().zip(&self.0)
+    }
+}